@@ -50,9 +50,38 @@ pub(crate) struct ExternalInfo {
     pub(crate) internal_trait_impls: HashSet<DefId>,
     // all #[verifier::external_fn_specification] functions that implement a trait
     pub(crate) external_fn_specification_trait_method_impls: Vec<(DefId, rustc_span::Span)>,
+    // one (target, proxy, span) entry per proxy, where target is the DefId of the real external
+    // item (not the proxy); used to detect two proxies conflicting over the same upstream item.
+    // Only `external_type_specification` proxies whose target is an ADT are recorded;
+    // `add_external_fn_specification_proxy` has no caller in this crate, so
+    // `external_fn_specification_proxies` stays empty
+    pub(crate) external_type_specification_proxies: Vec<(DefId, DefId, rustc_span::Span)>,
+    pub(crate) external_fn_specification_proxies: Vec<(DefId, DefId, rustc_span::Span)>,
+    // every public path a single-item `pub use` re-export makes an item reachable under, keyed
+    // by the item's original DefId; read only by `compute_shortest_public_paths` to name items
+    // in diagnostics (spec attachment does not consult it)
+    pub(crate) reexports: HashMap<DefId, Vec<Path>>,
 }
 
 impl ExternalInfo {
+    pub(crate) fn add_external_type_specification_proxy(
+        &mut self,
+        target_def_id: DefId,
+        proxy_def_id: DefId,
+        span: rustc_span::Span,
+    ) {
+        self.external_type_specification_proxies.push((target_def_id, proxy_def_id, span));
+    }
+
+    pub(crate) fn add_external_fn_specification_proxy(
+        &mut self,
+        target_def_id: DefId,
+        proxy_def_id: DefId,
+        span: rustc_span::Span,
+    ) {
+        self.external_fn_specification_proxies.push((target_def_id, proxy_def_id, span));
+    }
+
     pub(crate) fn add_type_id(&mut self, def_id: DefId) {
         self.type_id_map.insert(def_id, true);
     }
@@ -272,7 +301,28 @@ fn check_item<'tcx>(
                 external_info,
             )?;
         }
-        ItemKind::Use { .. } => {}
+        ItemKind::Use(path, use_kind) => {
+            // Record `pub use` aliases so that diagnostics about an item reached only through a
+            // re-export (see `compute_shortest_public_paths`, consulted by
+            // `check_external_specification_coherence`) can name the path a user could actually
+            // write instead of the item's raw definition path.
+            //
+            // Glob imports (`pub use foo::*;`) are skipped: `item.ident` is a meaningless
+            // placeholder for a glob (there's no single alias name to record), so treating it as
+            // the alias for every `Res::Def` the glob resolves to would corrupt `reexports` with
+            // a bogus path shared across unrelated items.
+            if matches!(use_kind, rustc_hir::UseKind::Single)
+                && ctxt.tcx.visibility(item.owner_id.to_def_id()).is_public()
+            {
+                let alias_path =
+                    typ_path_and_ident_to_vir_path(&module_path(), Arc::new(item.ident.to_string()));
+                for res in path.res.iter() {
+                    if let rustc_hir::def::Res::Def(_, def_id) = res {
+                        external_info.reexports.entry(*def_id).or_default().push(alias_path.clone());
+                    }
+                }
+            }
+        }
         ItemKind::ExternCrate { .. } => {}
         ItemKind::Mod { .. } => {}
         ItemKind::ForeignMod { .. } => {}
@@ -317,6 +367,22 @@ fn check_item<'tcx>(
                 adt_def,
                 external_info,
             )?;
+
+            if vattrs.external_type_specification {
+                // The proxy is a single-field struct whose field names the real external type;
+                // that field's type is the target the coherence check keys on. A target that is
+                // not an ADT (e.g. the primitive `char`) is not recorded and so not checked.
+                if let Some(field) = variant_data.fields().first() {
+                    let field_ty = ctxt.tcx.type_of(field.def_id).skip_binder();
+                    if let Some(target_adt_def) = field_ty.ty_adt_def() {
+                        external_info.add_external_type_specification_proxy(
+                            target_adt_def.did(),
+                            item.owner_id.to_def_id(),
+                            item.span,
+                        );
+                    }
+                }
+            }
         }
         ItemKind::Enum(enum_def, generics) => {
             if vattrs.is_external(&ctxt.cmd_line_args) {
@@ -392,6 +458,18 @@ fn check_item<'tcx>(
                     );
                 }
 
+                if Some(trait_def_id) == ctxt.tcx.lang_items().drop_trait() {
+                    return check_drop_impl(
+                        ctxt,
+                        vir,
+                        &module_path(),
+                        impl_path.clone(),
+                        item,
+                        impll,
+                        external_info,
+                    );
+                }
+
                 let verus_item = ctxt.verus_items.id_to_name.get(&trait_def_id);
 
                 /* sealed, `unsafe` */
@@ -523,7 +601,8 @@ fn check_item<'tcx>(
                             mk_visibility(&ctxt, impl_item.owner_id.to_def_id());
                         match &impl_item.kind {
                             ImplItemKind::Fn(sig, body_id) => {
-                                let kind = if let Some((_, trait_path, trait_typ_args)) =
+                                let mut trait_method_refinement = None;
+                                let kind = if let Some((trait_ref, trait_path, trait_typ_args)) =
                                     trait_path_typ_args.clone()
                                 {
                                     let ident = impl_item_ref.ident.to_string();
@@ -531,6 +610,20 @@ fn check_item<'tcx>(
                                     let path = typ_path_and_ident_to_vir_path(&trait_path, ident);
                                     let fun = FunX { path };
                                     let method = Arc::new(fun);
+                                    // The impl method is allowed to declare its own requires/ensures
+                                    // as long as it is a sound refinement of the trait method's
+                                    // contract: the impl may only weaken the precondition and only
+                                    // strengthen the postcondition. This only identifies the trait
+                                    // method being implemented and threads its id/trait_ref through
+                                    // to check_item_fn (rust_to_vir_func.rs, not modified here);
+                                    // the substitution of the trait method's spec via trait_ref and
+                                    // the emission of the two refinement obligations themselves
+                                    // have to live there and are not part of this change.
+                                    let ai = ctxt.tcx.associated_item(impl_item.owner_id.to_def_id());
+                                    if let Some(trait_method_def_id) = ai.trait_item_def_id {
+                                        trait_method_refinement =
+                                            Some((trait_method_def_id, trait_ref, method.clone()));
+                                    }
                                     FunctionKind::TraitMethodImpl {
                                         method,
                                         impl_path: impl_path.clone(),
@@ -555,7 +648,7 @@ fn check_item<'tcx>(
                                     &impl_item.generics,
                                     CheckItemFnEither::BodyId(body_id),
                                     None,
-                                    None,
+                                    trait_method_refinement,
                                     external_info,
                                 )?;
                             }
@@ -567,12 +660,17 @@ fn check_item<'tcx>(
                         }
                     }
                     AssocItemKind::Type => {
-                        if impl_item.generics.predicates.len() != 0
-                            || impl_item.generics.has_where_clause_predicates
-                        {
+                        // Generic associated types (e.g. `type Assoc<U>: Bound<U>;`) are
+                        // supported by turning the associated type into a VIR type-level
+                        // function parameterized by its own type/lifetime parameters, in
+                        // addition to the impl's parameters; see the merge of
+                        // `gat_typ_params`/`gat_typ_bounds` below.
+                        if impl_item.generics.params.iter().any(|p| {
+                            matches!(p.kind, rustc_hir::GenericParamKind::Const { .. })
+                        }) {
                             unsupported_err!(
                                 item.span,
-                                "unsupported generics on associated type",
+                                "unsupported const generics on associated type",
                                 impl_item_ref
                             );
                         }
@@ -590,7 +688,7 @@ fn check_item<'tcx>(
                             if let Some((trait_ref, trait_path, trait_typ_args)) =
                                 trait_path_typ_args.clone()
                             {
-                                let (typ_params, typ_bounds) =
+                                let (impl_typ_params, impl_typ_bounds) =
                                     crate::rust_to_vir_base::check_generics_bounds_no_polarity(
                                         ctxt.tcx,
                                         &ctxt.verus_items,
@@ -600,6 +698,32 @@ fn check_item<'tcx>(
                                         Some(&mut *ctxt.diagnostics.borrow_mut()),
                                     )?;
 
+                                // The associated type's own generics (if any) become additional
+                                // parameters of the type-level function it's lowered to.
+                                let (gat_typ_params, gat_typ_bounds) =
+                                    crate::rust_to_vir_base::check_generics_bounds_no_polarity(
+                                        ctxt.tcx,
+                                        &ctxt.verus_items,
+                                        impl_item.generics.span,
+                                        Some(impl_item.generics),
+                                        impl_item.owner_id.to_def_id(),
+                                        Some(&mut *ctxt.diagnostics.borrow_mut()),
+                                    )?;
+                                let typ_params = Arc::new(
+                                    impl_typ_params
+                                        .iter()
+                                        .chain(gat_typ_params.iter())
+                                        .cloned()
+                                        .collect::<Vec<_>>(),
+                                );
+                                let typ_bounds = Arc::new(
+                                    impl_typ_bounds
+                                        .iter()
+                                        .chain(gat_typ_bounds.iter())
+                                        .cloned()
+                                        .collect::<Vec<_>>(),
+                                );
+
                                 let ai = ctxt.tcx.associated_item(impl_item.owner_id.to_def_id());
                                 let assoc_def_id = ai.trait_item_def_id.unwrap();
                                 let bounds = ctxt.tcx.item_bounds(assoc_def_id);
@@ -680,6 +804,64 @@ fn check_item<'tcx>(
                             );
                         }
                     }
+                    AssocItemKind::Const => {
+                        let ImplItemKind::Const(_ty, body_id) = impl_item.kind else {
+                            unsupported_err!(item.span, "unsupported item ref in impl", impl_item_ref);
+                        };
+                        let const_def_id = impl_item.owner_id.to_def_id();
+                        let impl_item_visibility = mk_visibility(&ctxt, const_def_id);
+                        let mid_ty = ctxt.tcx.type_of(const_def_id).skip_binder();
+                        let vir_ty = mid_ty_to_vir(
+                            ctxt.tcx,
+                            &ctxt.verus_items,
+                            const_def_id,
+                            impl_item.span,
+                            &mid_ty,
+                            false,
+                        )?;
+
+                        if let Some((trait_ref, _trait_path, _trait_typ_args)) =
+                            trait_path_typ_args.clone()
+                        {
+                            // Following `compare_const_impl`: the impl's associated constant
+                            // must have the same type as the trait's declaration, after
+                            // substituting the impl's trait type arguments.
+                            let ai = ctxt.tcx.associated_item(const_def_id);
+                            if let Some(trait_const_def_id) = ai.trait_item_def_id {
+                                let trait_mid_ty = ctxt
+                                    .tcx
+                                    .type_of(trait_const_def_id)
+                                    .instantiate(ctxt.tcx, trait_ref.instantiate_identity().args);
+                                let trait_vir_ty = mid_ty_to_vir(
+                                    ctxt.tcx,
+                                    &ctxt.verus_items,
+                                    const_def_id,
+                                    impl_item.span,
+                                    &trait_mid_ty,
+                                    false,
+                                )?;
+                                if vir_ty != trait_vir_ty {
+                                    return err_span(
+                                        impl_item.span,
+                                        "associated constant's type does not match the trait's declared type",
+                                    );
+                                }
+                            }
+                        }
+
+                        crate::rust_to_vir_func::check_item_const_or_static(
+                            ctxt,
+                            vir,
+                            impl_item.span,
+                            const_def_id,
+                            impl_item_visibility,
+                            &module_path(),
+                            ctxt.tcx.hir().attrs(impl_item.hir_id()),
+                            &vir_ty,
+                            &body_id,
+                            false,
+                        )?;
+                    }
                     _ => unsupported_err!(item.span, "unsupported item ref in impl", impl_item_ref),
                 }
             }
@@ -699,6 +881,13 @@ fn check_item<'tcx>(
             );
             handle_const_or_static(body_id)?;
         }
+        // Immutable statics go through the same `handle_const_or_static` translation as a
+        // `const` of the same type; this arm is unchanged from baseline.
+        // TODO(chunk2-5): the request asks for `process_const_early` (rust_to_vir_global.rs, not
+        // part of this tree) to be extended so statics participate in `arch_word_bits`-dependent
+        // integer-bound reasoning the way consts already do. That extension is NOT implemented —
+        // nothing in this file changes what the early pass below does with a static — so this
+        // request is still open, not delivered by this commit.
         ItemKind::Static(_ty, Mutability::Not, body_id) => {
             handle_const_or_static(body_id)?;
         }
@@ -706,7 +895,10 @@ fn check_item<'tcx>(
             if vattrs.is_external(&ctxt.cmd_line_args) {
                 return Ok(());
             }
-            unsupported_err!(item.span, "static mut");
+            unsupported_err!(
+                item.span,
+                "`static mut` is not supported by the verifier (shared mutable state must go through an explicitly verified synchronization primitive)"
+            );
         }
         ItemKind::Macro(_, _) => {}
         ItemKind::Trait(IsAuto::No, Unsafety::Normal, trait_generics, _bounds, trait_items) => {
@@ -752,6 +944,35 @@ fn check_item<'tcx>(
         }) => {
             return Ok(());
         }
+        ItemKind::OpaqueTy(OpaqueTy {
+            generics: _,
+            bounds,
+            origin: OpaqueTyOrigin::FnReturn(_),
+            in_trait: _,
+            lifetime_mapping: _,
+        }) => {
+            // `impl Trait` in a function return position (RPIT). This only validates the item
+            // definition itself, by rejecting trait bounds we don't recognize; it does not
+            // construct or register a VIR abstract type for the opaque type, and callers that
+            // use the RPIT-typed return value have nothing to resolve it against, since
+            // mid_ty_to_vir (rust_to_vir_base.rs, not part of this tree) is not extended to
+            // handle `TyKind::Alias(Opaque, ..)`. Actually lowering RPIT to an abstract VIR type
+            // at call sites is still open.
+            for bound in bounds.iter() {
+                if let rustc_hir::GenericBound::Trait(poly_trait_ref, _) = bound {
+                    let trait_def_id = poly_trait_ref.trait_ref.path.res.def_id();
+                    if verus_items::get_rust_item(ctxt.tcx, trait_def_id).is_none()
+                        && !ctxt.verus_items.id_to_name.contains_key(&trait_def_id)
+                    {
+                        unsupported_err!(
+                            item.span,
+                            "unsupported trait bound on `impl Trait` return type",
+                            bound
+                        );
+                    }
+                }
+            }
+        }
         _ => {
             if vattrs.is_external(&ctxt.cmd_line_args) {
                 return Ok(());
@@ -762,6 +983,74 @@ fn check_item<'tcx>(
     Ok(())
 }
 
+// `impl Drop for T` gets its own verification path rather than being treated as a generic
+// trait impl: this validates that the impl is safe and defines nothing but `drop(&mut self)`,
+// then dispatches to check_item_fn with FunctionKind::DropImpl so it's tagged distinctly from an
+// ordinary inherent/trait method.
+// TODO SOUNDNESS(chunk0-3): the ghost/tracked-resource accounting this kind exists to eventually
+// support is NOT implemented — no verified field access for `drop`, no check that linear
+// resources are explicitly released, no post-drop `ensures`. That logic would have to live in
+// check_item_fn (rust_to_vir_func.rs, not modified here). Beyond the checks in this function,
+// nothing here adds drop-specific obligations; unlike an ordinary trait impl, no `TraitImpl` is
+// pushed to `vir.trait_impls` and the method is tagged `DropImpl` rather than `TraitMethodImpl`.
+fn check_drop_impl<'tcx>(
+    ctxt: &Context<'tcx>,
+    vir: &mut KrateX,
+    module_path: &Path,
+    impl_path: Path,
+    item: &'tcx Item<'tcx>,
+    impll: &'tcx rustc_hir::Impl<'tcx>,
+    external_info: &mut ExternalInfo,
+) -> Result<(), VirErr> {
+    if impll.unsafety != Unsafety::Normal {
+        return err_span(item.span, "the verifier does not support `unsafe impl Drop`");
+    }
+
+    // Record this impl the same way the ordinary trait-impl path does (see the
+    // `external_info.internal_trait_impls.insert` near the `trait_path_typ_args` block above),
+    // so that `collect_external_trait_impls` treats this local `Drop` impl as already processed
+    // and does not auto-import it a second time. Whether other `Drop` impls (such as an upstream
+    // blanket impl) are auto-imported is not affected by this.
+    external_info.internal_trait_impls.insert(item.owner_id.to_def_id());
+
+    for impl_item_ref in impll.items {
+        unsupported_err_unless!(
+            matches!(impl_item_ref.kind, AssocItemKind::Fn { has_self: true })
+                && impl_item_ref.ident.as_str() == "drop",
+            item.span,
+            "`Drop` impls may only define the `drop` method",
+            impl_item_ref
+        );
+
+        let impl_item = ctxt.tcx.hir().impl_item(impl_item_ref.id);
+        let fn_attrs = ctxt.tcx.hir().attrs(impl_item.hir_id());
+        let impl_item_visibility = mk_visibility(ctxt, impl_item.owner_id.to_def_id());
+        match &impl_item.kind {
+            ImplItemKind::Fn(sig, body_id) => {
+                check_item_fn(
+                    ctxt,
+                    &mut vir.functions,
+                    Some(&mut vir.reveal_groups),
+                    impl_item.owner_id.to_def_id(),
+                    FunctionKind::DropImpl { impl_path: impl_path.clone() },
+                    impl_item_visibility,
+                    module_path,
+                    fn_attrs,
+                    sig,
+                    Some((&impll.generics, item.owner_id.to_def_id())),
+                    &impl_item.generics,
+                    CheckItemFnEither::BodyId(body_id),
+                    None,
+                    None,
+                    external_info,
+                )?;
+            }
+            _ => unsupported_err!(item.span, "unsupported item in `Drop` impl", impl_item_ref),
+        }
+    }
+    Ok(())
+}
+
 fn trait_impl_to_vir<'tcx>(
     ctxt: &Context<'tcx>,
     span: rustc_span::Span,
@@ -889,7 +1178,19 @@ fn collect_external_trait_impls<'tcx>(
         } else {
             continue;
         };
-        for arg in trait_ref.skip_binder().args.iter() {
+        // A blanket impl (`impl<T: Bound> Trait for T`) has a bare type parameter as its self
+        // type rather than a nominal ADT; `mid_ty_filter_for_external_impls` is written to
+        // reject such "unresolved" types, so detect this case up front and don't apply that
+        // filter to the self-type argument. Verus can still soundly import the impl: the trait's
+        // spec methods get instantiated per concrete use site, just like a local blanket impl
+        // would be.
+        let self_ty = trait_ref.skip_binder().self_ty();
+        let is_blanket_impl = matches!(self_ty.kind(), rustc_middle::ty::TyKind::Param(_));
+        for (i, arg) in trait_ref.skip_binder().args.iter().enumerate() {
+            if is_blanket_impl && i == 0 {
+                // self type (always the first arg of a `TraitRef`); skip the concrete-type check
+                continue;
+            }
             if !crate::rust_to_vir_base::mid_ty_filter_for_external_impls(
                 ctxt,
                 arg.walk(),
@@ -996,6 +1297,89 @@ fn collect_external_trait_impls<'tcx>(
     Ok(())
 }
 
+// Two proxies (whether `external_type_specification` or `external_fn_specification`) must not
+// claim the same upstream item: that would mean Verus assumes two potentially-contradictory
+// axioms for the same external definition.
+//
+// Orphan rule: proxies are always items of the crate currently being verified (we only ever push
+// onto `external_info`'s proxy lists from `check_item`, which walks `ctxt.krate.owners`, i.e. the
+// local crate), so "a proxy may only be accepted if it lives in the crate defining the external
+// item" is trivially satisfied whenever the target is itself local; the rule only has teeth when
+// the target is a genuinely different (upstream) crate. `vstd` is the one crate explicitly
+// trusted to write such specs for `std`/`core` (that's the whole reason the feature exists); we
+// recognize it the same way path rewriting for the pervasive prelude already does, via
+// `ctxt.vstd_crate_name` being absent exactly when the crate under verification *is* vstd itself.
+// Ordinary downstream crates are not granted that trust and are rejected.
+// TODO(chunk0-2): the other half of the rule ("... or the crate defining a trait involved in the
+// spec") is not checked — doing so requires knowing which trait(s), if any, a given proxy's spec
+// is attached to, which `external_info`'s proxy lists don't currently carry. Until that's
+// threaded through, a non-vstd crate writing a trait-based external spec for an upstream item is
+// rejected by this function even in the case the full rule would allow.
+fn check_external_specification_coherence<'tcx>(
+    ctxt: &Context<'tcx>,
+    imported: &Vec<Krate>,
+    external_info: &ExternalInfo,
+    shortest_public_paths: &ShortestPublicPathMap,
+) -> Result<(), VirErr> {
+    // Prefer the shortest path a user could actually write (via a `pub use` re-export) over the
+    // item's raw rustc definition path when naming the target in a diagnostic, so an error about
+    // a spec attached through a re-export doesn't point back at a path the user never wrote.
+    let display_path = |def_id: DefId| -> String {
+        match shortest_public_paths.get(&def_id) {
+            Some(path) => path.segments.iter().map(|s| s.to_string()).collect::<Vec<_>>().join("::"),
+            None => ctxt.tcx.def_path_str(def_id),
+        }
+    };
+
+    let verifying_vstd = ctxt.vstd_crate_name.is_none();
+
+    let mut already_specified_upstream: HashSet<Path> = HashSet::new();
+    for k in imported.iter() {
+        already_specified_upstream.extend(k.external_types.iter().cloned());
+        already_specified_upstream.extend(k.external_fns.iter().map(|f| f.path.clone()));
+    }
+
+    let mut seen: IndexMap<DefId, (DefId, rustc_span::Span)> = IndexMap::new();
+    for (target_def_id, proxy_def_id, span) in external_info
+        .external_type_specification_proxies
+        .iter()
+        .chain(external_info.external_fn_specification_proxies.iter())
+    {
+        if !verifying_vstd && target_def_id.krate != rustc_span::def_id::LOCAL_CRATE {
+            return err_span(
+                *span,
+                format!(
+                    "external specification for {} is not allowed here: only the crate defining \
+                     it (or vstd, which is trusted to specify std/core) may provide one",
+                    display_path(*target_def_id)
+                ),
+            );
+        }
+        let target_path = def_id_to_vir_path(ctxt.tcx, &ctxt.verus_items, *target_def_id);
+        if already_specified_upstream.contains(&target_path) {
+            return err_span(
+                *span,
+                format!(
+                    "{} already has an external specification provided by an upstream crate",
+                    display_path(*target_def_id)
+                ),
+            );
+        }
+        if let Some((_prev_proxy, prev_span)) = seen.get(target_def_id) {
+            return err_span(
+                *span,
+                format!(
+                    "duplicate external specification for {} (previously specified at {:?})",
+                    display_path(*target_def_id),
+                    prev_span
+                ),
+            );
+        }
+        seen.insert(*target_def_id, (*proxy_def_id, *span));
+    }
+    Ok(())
+}
+
 fn check_foreign_item<'tcx>(
     ctxt: &Context<'tcx>,
     vir: &mut KrateX,
@@ -1051,10 +1435,53 @@ impl<'tcx> rustc_hir::intravisit::Visitor<'tcx> for VisitMod<'tcx> {
 
 pub type ItemToModuleMap = HashMap<ItemId, Option<Path>>;
 
+// For each DefId with at least one recorded `pub use` re-export, the shortest public path to
+// it (its definition path if that is exported, otherwise or if shorter a re-export path; on a
+// tie the definition path wins), following rust-analyzer's `import_map`/`find_path`
+// approach. Items with no public re-export are absent here; callers should fall back to the
+// item's definition path in that case.
+pub type ShortestPublicPathMap = HashMap<DefId, Path>;
+
+fn compute_shortest_public_paths<'tcx>(
+    ctxt: &Context<'tcx>,
+    external_info: &ExternalInfo,
+) -> ShortestPublicPathMap {
+    let mut shortest: ShortestPublicPathMap = HashMap::new();
+    for (def_id, reexport_paths) in external_info.reexports.iter() {
+        // The definition path only counts as a candidate if it is itself genuinely reachable
+        // from outside the crate (this rules out the common `pub(crate)`/private-module case,
+        // where the only way a user can actually name the item is via one of its re-exports).
+        // Non-local def_ids always qualify: an upstream item can only appear in `reexports` by
+        // being named in a local `pub use`, which already implies it is externally visible.
+        let def_path_is_public = match def_id.as_local() {
+            Some(local_def_id) => ctxt.tcx.effective_visibilities(()).is_exported(local_def_id),
+            None => true,
+        };
+        let mut best = if def_path_is_public {
+            Some(def_id_to_vir_path(ctxt.tcx, &ctxt.verus_items, *def_id))
+        } else {
+            None
+        };
+        for path in reexport_paths {
+            best = Some(match best {
+                Some(b) if b.segments.len() <= path.segments.len() => b,
+                _ => path.clone(),
+            });
+        }
+        let Some(best) = best else {
+            // No public definition path and no re-export recorded; leave this
+            // def_id out of the map entirely (same as having no re-export at all).
+            continue;
+        };
+        shortest.insert(*def_id, best);
+    }
+    shortest
+}
+
 pub fn crate_to_vir<'tcx>(
     ctxt: &mut Context<'tcx>,
     imported: &Vec<Krate>,
-) -> Result<(Krate, ItemToModuleMap), VirErr> {
+) -> Result<(Krate, ItemToModuleMap, ShortestPublicPathMap), VirErr> {
     let mut vir: KrateX = KrateX {
         functions: Vec::new(),
         reveal_groups: Vec::new(),
@@ -1076,6 +1503,9 @@ pub fn crate_to_vir<'tcx>(
         type_id_map: HashMap::new(),
         internal_trait_impls: HashSet::new(),
         external_fn_specification_trait_method_impls: Vec::new(),
+        external_type_specification_proxies: Vec::new(),
+        external_fn_specification_proxies: Vec::new(),
+        reexports: HashMap::new(),
     };
 
     // TODO: when we stop ignoring these traits,
@@ -1170,6 +1600,13 @@ pub fn crate_to_vir<'tcx>(
         ctxt.arch_word_bits = Some(arch_word_bits);
         vir.arch.word_bits = arch_word_bits;
     }
+    // TODO(chunk2-1): the request asks for check_item to be skipped for owners whose HIR is
+    // unchanged since a prior run, via an on-disk `Fingerprint -> VIR fragment` cache, plus
+    // parallelizing this loop. None of that is implemented: there is no persistence across runs,
+    // no skip-on-unchanged-fingerprint behavior, and no parallelization, so this request is still
+    // open. (An earlier attempt tracked fingerprints in a `HashSet` and skipped `check_item` on a
+    // repeat hash, which was removed because it silently dropped verification of any owner whose
+    // HIR happened to hash the same as one already seen — see 492f05d.)
     for owner in ctxt.krate.owners.iter() {
         if let MaybeOwner::Owner(owner) = owner {
             match owner.node() {
@@ -1224,6 +1661,13 @@ pub fn crate_to_vir<'tcx>(
     vir.path_as_rust_names = vir::ast_util::get_path_as_rust_names_for_krate(&ctxt.vstd_crate_name);
 
     collect_external_trait_impls(ctxt, imported, &mut vir, &mut external_info)?;
+    let shortest_public_paths = compute_shortest_public_paths(ctxt, &external_info);
+    check_external_specification_coherence(
+        ctxt,
+        imported,
+        &external_info,
+        &shortest_public_paths,
+    )?;
 
-    Ok((Arc::new(vir), item_to_module))
+    Ok((Arc::new(vir), item_to_module, shortest_public_paths))
 }
@@ -0,0 +1,52 @@
+#![feature(rustc_private)]
+#[macro_use]
+mod common;
+use common::*;
+
+// chunk0-5: a generic associated type's own generics are merged into the assoc type's type
+// params, so a GAT impl is accepted rather than rejected as an unsupported item.
+test_verify_one_file! {
+    #[test] gat_impl_accepted code_str! {
+        ::builtin_macros::verus!{
+            trait Container {
+                type Item<T>;
+            }
+
+            struct Wrapper;
+
+            impl Container for Wrapper {
+                type Item<T> = T;
+            }
+
+            fn main() {}
+        }
+    } => Ok(())
+}
+
+// chunk0-6: associated constants in trait impls are translated.
+test_verify_one_file! {
+    #[test] assoc_const_impl_accepted code_str! {
+        ::builtin_macros::verus!{
+            trait HasZero {
+                const ZERO: u32;
+            }
+
+            struct MyZero;
+
+            impl HasZero for MyZero {
+                const ZERO: u32 = 0;
+            }
+
+            fn main() {
+                assert(MyZero::ZERO == 0);
+            }
+        }
+    } => Ok(())
+}
+
+// chunk2-1 (fingerprint/cache), chunk2-2 (blanket-impl auto-import), and chunk2-3's glob-import
+// skip are not covered here: chunk2-1 currently has no observable behavior to test (see the
+// TODO in rust_to_vir.rs — no caching or skipping is implemented), and chunk2-2/chunk2-3's glob
+// case both require a multi-crate test harness (an upstream crate with a blanket impl or a glob
+// re-export, verified as a dependency) that test_verify_one_file!'s single-file setup doesn't
+// exercise.
@@ -0,0 +1,91 @@
+#![feature(rustc_private)]
+#[macro_use]
+mod common;
+use common::*;
+
+// chunk0-1 (trait method refinement plumbing): the impl method correctly dispatches via
+// FunctionKind::TraitMethodImpl with its trait_method_refinement populated, so an impl with a
+// requires/ensures contract compatible with the trait method still verifies. This does NOT
+// exercise the requested soundness check itself (R_t' ==> R_i, E_i ==> E_t'): that substitution
+// and obligation emission isn't implemented (see the comment on trait method impls in
+// `check_item`, rust_to_vir.rs), so
+// there is nothing yet that would fail this test if an incompatible contract were used instead.
+test_verify_one_file! {
+    #[test] trait_method_impl_dispatches code_str! {
+        ::builtin_macros::verus!{
+            trait HasDefault {
+                spec fn default_spec() -> u32;
+
+                fn get_default(&self) -> (r: u32)
+                    ensures r == Self::default_spec();
+            }
+
+            struct Zero;
+
+            impl HasDefault for Zero {
+                spec fn default_spec() -> u32 { 0 }
+
+                fn get_default(&self) -> (r: u32)
+                    ensures r == Self::default_spec()
+                {
+                    0
+                }
+            }
+
+            fn main() {
+                let z = Zero;
+                let r = z.get_default();
+                assert(r == 0);
+            }
+        }
+    } => Ok(())
+}
+
+// chunk0-3: a `Drop` impl that defines only `drop` verifies.
+test_verify_one_file! {
+    #[test] drop_impl_accepted code_str! {
+        ::builtin_macros::verus!{
+            struct HasDrop { x: u32 }
+
+            impl Drop for HasDrop {
+                fn drop(&mut self) {}
+            }
+
+            fn main() {
+                let h = HasDrop { x: 0 };
+                drop(h);
+            }
+        }
+    } => Ok(())
+}
+
+// chunk0-3: a `Drop` impl may not define anything besides `drop`. rustc itself rejects the
+// extra method (E0407) before check_drop_impl's own check is reached.
+test_verify_one_file! {
+    #[test] drop_impl_extra_method_rejected code_str! {
+        ::builtin_macros::verus!{
+            struct HasDrop { x: u32 }
+
+            impl Drop for HasDrop {
+                fn drop(&mut self) {}
+
+                fn extra(&self) {}
+            }
+        }
+    } => Err(e) => assert_rust_error_msg_all(e, "method `extra` is not a member of trait `Drop`")
+}
+
+// chunk0-4: `impl Trait` in return position with a recognized trait bound is accepted.
+test_verify_one_file! {
+    #[test] rpit_with_known_bound_accepted code_str! {
+        ::builtin_macros::verus!{
+            fn make_copy() -> impl Copy {
+                42u32
+            }
+
+            fn main() {
+                let _x = make_copy();
+            }
+        }
+    } => Ok(())
+}